@@ -1,13 +1,17 @@
 use anyhow::anyhow;
-use clap::{command, Parser, Subcommand};
+use clap::{command, Parser, Subcommand, ValueEnum};
 use mask_parser::maskfile::Script;
 use owo_colors::OwoColorize;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashMap,
     fmt::{Debug, Display},
     fs::{self, File},
     io::{self, Write},
     path::{Path, PathBuf},
     process::Command,
+    thread,
 };
 
 #[derive(Parser)]
@@ -17,14 +21,34 @@ struct Cli {
     /// Path to a different maskfile you want to use
     maskfile: String,
 
+    #[arg(global = true, long, value_enum, default_value_t = OutputFormat::Human)]
+    /// Output format for findings
+    format: OutputFormat,
+
     #[command(subcommand)]
     command: Commands,
 }
 
+/// How findings from `Commands::Run` are rendered.
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Colorized, per-command text output.
+    Human,
+    /// A flat JSON array of `Finding`s.
+    Json,
+    /// A SARIF 2.1.0 run, for consumption by editors and CI.
+    Sarif,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Runs the linters.
     Run {},
+    /// Runs the linters and writes autocorrections back into the maskfile.
+    Fix {},
+    /// Checks each command's `masklint-expect` annotations against its
+    /// actual findings and fails on any mismatch.
+    Test {},
     /// Extracts all the commands from the maskfile and dumps them as files
     /// into the defined directory.
     Dump {
@@ -33,11 +57,151 @@ enum Commands {
     },
 }
 
+/// What `collect_jobs` and `run_job` should do with each extracted script.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// Just dump the extracted scripts to disk.
+    Dump,
+    /// Lint the scripts and report findings.
+    Run,
+    /// Lint the scripts, autocorrect them, and splice the result back into
+    /// the maskfile.
+    Fix,
+    /// Lint the scripts and diff the findings against `masklint-expect`
+    /// annotations in their source.
+    Test,
+}
+
+/// A single normalized linter diagnostic, already mapped back to the
+/// maskfile's own coordinates.
+#[derive(Serialize)]
+struct Finding {
+    command: String,
+    line: u32,
+    column: Option<u32>,
+    code: Option<String>,
+    severity: Severity,
+    message: String,
+}
+
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    /// The SARIF `level` this severity corresponds to.
+    fn sarif_level(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "note",
+        }
+    }
+}
+
+/// Serializes findings as a SARIF 2.1.0 log with a single run.
+fn to_sarif(findings: &[Finding], maskfile: &str) -> serde_json::Value {
+    let results: Vec<serde_json::Value> = findings
+        .iter()
+        .map(|f| {
+            serde_json::json!({
+                "ruleId": f.code.clone().unwrap_or_else(|| "masklint".to_string()),
+                "level": f.severity.sarif_level(),
+                "message": { "text": f.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": maskfile },
+                        "region": {
+                            "startLine": f.line,
+                            "startColumn": f.column,
+                        }
+                    }
+                }]
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "version": "2.1.0",
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "runs": [{
+            "tool": { "driver": { "name": "masklint", "informationUri": "https://github.com/drew-dirac/masklint" } },
+            "results": results,
+        }]
+    })
+}
+
+/// A pending autocorrection, expressed as a byte range in the original
+/// maskfile source to replace with `replacement`.
+struct Patch {
+    start: usize,
+    end: usize,
+    replacement: String,
+}
+
+/// Returns the 1-indexed line number of the given byte offset in `source`.
+fn line_number_at(source: &str, byte_offset: usize) -> u32 {
+    1 + source[..byte_offset].bytes().filter(|&b| b == b'\n').count() as u32
+}
+
+/// Locates a command's code fence in the maskfile source, so findings/fixes
+/// can be mapped back to the right place. `mask_parser::maskfile::Script`
+/// doesn't expose the fence's byte span, so this anchors the search to the
+/// command's own heading (searched no earlier than `after`, to keep sibling
+/// commands in document order) before looking for `script_source` — that
+/// rules out collisions with identical text in prose or other commands'
+/// fences elsewhere in the file. Returns `None`, rather than guessing, if
+/// the heading can't be found or the body doesn't appear under it.
+fn locate_fence(maskfile_source: &str, after: usize, command_name: &str, script_source: &str) -> Option<usize> {
+    let heading = Regex::new(&format!(r"(?m)^#+[ \t]*{}\b", regex::escape(command_name))).ok()?;
+    let heading_match = heading.find_at(maskfile_source, after)?;
+    let relative = maskfile_source[heading_match.end()..].find(script_source)?;
+    Some(heading_match.end() + relative)
+}
+
+/// Rewrites every `line N` occurrence in a handler's findings so `N` points
+/// at the maskfile line instead of the extracted temp-file line. `header_lines`
+/// accounts for lines a handler injected ahead of the script body (e.g.
+/// Shellcheck's shebang), and `fence_start_line` is the maskfile line where
+/// the command's code fence body begins.
+fn remap_lines(text: &str, fence_start_line: u32, header_lines: u32) -> String {
+    const MARKER: &str = "line ";
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(idx) = rest.find(MARKER) {
+        result.push_str(&rest[..idx]);
+        result.push_str(MARKER);
+        rest = &rest[idx + MARKER.len()..];
+
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            continue;
+        }
+        let reported_line: u32 = rest[..digits].parse().unwrap_or(1);
+        let mapped_line = fence_start_line + reported_line.saturating_sub(1 + header_lines);
+        result.push_str(&mapped_line.to_string());
+        rest = &rest[digits..];
+    }
+    result.push_str(rest);
+    result
+}
+
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
-    let content = fs::read_to_string(cli.maskfile)?;
-    let maskfile = mask_parser::parse(content);
+    let content = fs::read_to_string(&cli.maskfile)?;
+    let maskfile = mask_parser::parse(content.clone());
+
+    let mode = match &cli.command {
+        Commands::Dump { .. } => Mode::Dump,
+        Commands::Fix {} => Mode::Fix,
+        Commands::Run {} => Mode::Run,
+        Commands::Test {} => Mode::Test,
+    };
 
     // keeping the _tmp dir here to not let it go out of scope
     let (out_dir, _tmp) = match &cli.command {
@@ -52,8 +216,21 @@ fn main() -> anyhow::Result<()> {
         }
     };
 
-    // Function to process a command and its subcommands
-    fn process_command(command: mask_parser::maskfile::Command, out_dir: &PathBuf, is_dump: bool, parent_name: Option<&str>) -> anyhow::Result<()> {
+    // Walks a command and its subcommands, writing each script to a temp
+    // file and queuing a `Job` for the ones that need linting. `cursor`
+    // tracks how far into `maskfile_source` we've already matched, so that
+    // commands with identical script bodies each resolve to their own,
+    // distinct, in-order fence rather than all piling onto the first match.
+    fn collect_jobs<'r>(
+        command: mask_parser::maskfile::Command,
+        out_dir: &PathBuf,
+        mode: Mode,
+        parent_name: Option<&str>,
+        maskfile_source: &str,
+        cursor: &mut usize,
+        registry: &'r HashMap<String, Box<dyn LanguageHandler>>,
+        jobs: &mut Vec<Job<'r>>,
+    ) -> anyhow::Result<()> {
         // Build full command name including parent
         let full_command_name = if let Some(parent) = parent_name {
             format!("{} {}", parent, command.name)
@@ -62,12 +239,11 @@ fn main() -> anyhow::Result<()> {
         };
 
         if let Some(script) = command.script {
-            let language_handler: &dyn LanguageHandler = match script.executor.as_str() {
-                "sh" | "bash" | "zsh" => &Shellcheck {},
-                "py" | "python" => &Ruff {},
-                "rb" | "ruby" => &Rubocop {},
-                _ => &Catchall {},
-            };
+            static CATCHALL: Catchall = Catchall;
+            let language_handler: &dyn LanguageHandler = registry
+                .get(script.executor.as_str())
+                .map(|handler| handler.as_ref())
+                .unwrap_or(&CATCHALL);
 
             let mut file_name = full_command_name.replace(" ", "_");
             file_name.push_str(language_handler.file_extension());
@@ -76,17 +252,24 @@ fn main() -> anyhow::Result<()> {
             let content = language_handler.content(&script)?;
             script_file.write_all(content.as_bytes())?;
 
-            if !is_dump {
-                let findings = language_handler.execute(&file_path).map_err(|e| match e.kind() {
-                    io::ErrorKind::NotFound => {
-                        anyhow!("executable for {language_handler} not found in $PATH")
-                    }
-                    _ => anyhow!(e),
-                })?;
-                if !findings.is_empty() {
-                    println!("{}", full_command_name.bold().cyan().underline());
-                    println!("{findings}\n");
+            if mode != Mode::Dump {
+                let fence_start = locate_fence(maskfile_source, *cursor, &full_command_name, &script.source);
+                match fence_start {
+                    Some(start) => *cursor = start + script.source.len(),
+                    None => eprintln!(
+                        "{} couldn't locate `{full_command_name}`'s code fence in the maskfile; \
+                         its line numbers won't be remapped and it will be skipped in Fix mode",
+                        "warning:".yellow()
+                    ),
                 }
+                jobs.push(Job {
+                    command_name: full_command_name.clone(),
+                    handler: language_handler,
+                    file_path,
+                    script_source: script.source,
+                    fence_start,
+                    fence_start_line: fence_start.map(|start| line_number_at(maskfile_source, start)),
+                });
             }
         }
 
@@ -98,27 +281,298 @@ fn main() -> anyhow::Result<()> {
                 command.name
             };
             for subcmd in command.subcommands {
-                process_command(subcmd, out_dir, is_dump, Some(&parent_name))?;
+                collect_jobs(subcmd, out_dir, mode, Some(&parent_name), maskfile_source, cursor, registry, jobs)?;
             }
         }
         Ok(())
     }
 
-    let is_dump = matches!(cli.command, Commands::Dump { .. });
+    let registry = build_registry(Path::new(&cli.maskfile));
+    let mut jobs: Vec<Job> = vec![];
+    let mut cursor = 0usize;
     for command in maskfile.commands {
-        process_command(command, &out_dir, is_dump, None)?;
+        collect_jobs(command, &out_dir, mode, None, &content, &mut cursor, &registry, &mut jobs)?;
+    }
+
+    // Run every job's linter concurrently, in bounded-size batches, since
+    // each is an independent external process. Batches (and join order
+    // within a batch) preserve the original command order so output stays
+    // deterministic regardless of scheduling.
+    let mut outputs: Vec<anyhow::Result<JobOutput>> = Vec::with_capacity(jobs.len());
+    for batch in jobs.chunks(MAX_CONCURRENT_JOBS) {
+        let batch_outputs = thread::scope(|scope| {
+            let handles: Vec<_> = batch
+                .iter()
+                .map(|job| scope.spawn(move || run_job(job, mode, cli.format)))
+                .collect();
+            handles.into_iter().map(|handle| handle.join().expect("linter thread panicked")).collect::<Vec<_>>()
+        });
+        outputs.extend(batch_outputs);
+    }
+
+    let mut patches: Vec<Patch> = vec![];
+    let mut findings: Vec<Finding> = vec![];
+    let mut test_failures = 0;
+    for output in outputs {
+        let output = output?;
+        if let Some(findings_text) = output.findings_text {
+            println!("{}", output.command_name.bold().cyan().underline());
+            println!("{findings_text}\n");
+        }
+        findings.extend(output.findings_structured);
+        if let Some(patch) = output.patch {
+            patches.push(patch);
+        }
+        if let Some(test_outcome) = output.test_outcome {
+            if !test_outcome.missing.is_empty() || !test_outcome.unexpected.is_empty() {
+                test_failures += 1;
+                println!("{}", output.command_name.bold().cyan().underline());
+                for expectation in &test_outcome.missing {
+                    println!("  {} {} line {}", "missing:".red(), expectation.code, expectation.line);
+                }
+                for finding in &test_outcome.unexpected {
+                    let code = finding.code.as_deref().unwrap_or("?");
+                    println!(
+                        "  {} {} line {}: {}",
+                        "unexpected:".yellow(),
+                        code,
+                        finding.line,
+                        finding.message
+                    );
+                }
+                println!();
+            }
+        }
+    }
+
+    if mode == Mode::Run && cli.format != OutputFormat::Human {
+        let output = match cli.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&findings)?,
+            OutputFormat::Sarif => serde_json::to_string_pretty(&to_sarif(&findings, &cli.maskfile))?,
+            OutputFormat::Human => unreachable!(),
+        };
+        println!("{output}");
     }
+
+    if mode == Mode::Fix {
+        // Apply patches from the end of the file towards the start so that
+        // earlier byte offsets stay valid as we splice.
+        patches.sort_by(|a, b| b.start.cmp(&a.start));
+        let mut fixed_content = content;
+        for patch in &patches {
+            fixed_content.replace_range(patch.start..patch.end, &patch.replacement);
+        }
+        fs::write(&cli.maskfile, fixed_content)?;
+        println!("Fixed {} command(s)", patches.len());
+    }
+
+    if mode == Mode::Test {
+        if test_failures > 0 {
+            return Err(anyhow!("{test_failures} command(s) didn't match their expected diagnostics"));
+        }
+        println!("All {} command(s) matched their expected diagnostics", jobs.len());
+    }
+
     Ok(())
 }
 
-trait LanguageHandler: Display {
-    fn file_extension(&self) -> &'static str {
+/// How many linter subprocesses `main` runs at once.
+const MAX_CONCURRENT_JOBS: usize = 8;
+
+/// A script that's been written to a temp file and is ready to lint,
+/// collected up front so linting can run concurrently across commands.
+struct Job<'r> {
+    command_name: String,
+    handler: &'r dyn LanguageHandler,
+    file_path: PathBuf,
+    /// The script's exact source, used to locate/splice its fence in Fix mode.
+    script_source: String,
+    /// Byte offset of `script_source` within the maskfile, if found.
+    fence_start: Option<usize>,
+    /// Maskfile line where the command's code fence body begins, or `None`
+    /// if the fence couldn't be located (a warning is printed when that
+    /// happens; callers should skip remapping rather than guess).
+    fence_start_line: Option<u32>,
+}
+
+/// The result of linting (or fixing) a single `Job`.
+#[derive(Default)]
+struct JobOutput {
+    command_name: String,
+    /// Human-format findings, already remapped to maskfile line numbers.
+    findings_text: Option<String>,
+    /// Structured findings, for the `json`/`sarif` formats.
+    findings_structured: Vec<Finding>,
+    /// A pending autocorrection, in Fix mode.
+    patch: Option<Patch>,
+    /// Diff against the script's `masklint-expect` annotations, in Test mode.
+    test_outcome: Option<TestOutcome>,
+}
+
+/// A `# masklint-expect: CODE line N` annotation pulled out of a script.
+#[derive(Clone)]
+struct Expectation {
+    code: String,
+    line: u32,
+}
+
+/// Parses `masklint-expect` annotations out of a script's source. The
+/// annotation's line number counts from the start of the script body
+/// itself (not the temp file), so authors can write it against the
+/// maskfile they're looking at.
+fn parse_expectations(source: &str) -> Vec<Expectation> {
+    const MARKER: &str = "masklint-expect:";
+    source
+        .lines()
+        .filter_map(|line| {
+            let rest = &line[line.find(MARKER)? + MARKER.len()..];
+            let mut words = rest.split_whitespace();
+            let code = words.next()?.to_string();
+            if words.next()? != "line" {
+                return None;
+            }
+            let line = words.next()?.parse().ok()?;
+            Some(Expectation { code, line })
+        })
+        .collect()
+}
+
+/// What diverged between a script's `masklint-expect` annotations and its
+/// actual findings.
+#[derive(Default)]
+struct TestOutcome {
+    missing: Vec<Expectation>,
+    unexpected: Vec<Finding>,
+}
+
+/// Runs the linter (or autocorrector) for a single job. Safe to call from
+/// any thread: it only touches `job`'s own temp file.
+fn run_job(job: &Job, mode: Mode, format: OutputFormat) -> anyhow::Result<JobOutput> {
+    let handler = job.handler;
+    let mut output = JobOutput {
+        command_name: job.command_name.clone(),
+        ..Default::default()
+    };
+
+    let not_found = |e: io::Error| match e.kind() {
+        io::ErrorKind::NotFound => anyhow!("executable for {handler} not found in $PATH"),
+        _ => anyhow!(e),
+    };
+
+    match mode {
+        Mode::Dump => {}
+        Mode::Run => {
+            if format == OutputFormat::Human {
+                let findings = handler.execute(&job.file_path).map_err(not_found)?;
+                if !findings.is_empty() {
+                    output.findings_text = Some(match job.fence_start_line {
+                        Some(fence_start_line) => remap_lines(&findings, fence_start_line, handler.header_lines()),
+                        None => findings,
+                    });
+                }
+            } else {
+                let mut findings = handler
+                    .execute_structured(&job.file_path, &job.command_name)
+                    .map_err(not_found)?;
+                if let Some(fence_start_line) = job.fence_start_line {
+                    for finding in &mut findings {
+                        finding.line = fence_start_line + finding.line.saturating_sub(1 + handler.header_lines());
+                    }
+                }
+                output.findings_structured = findings;
+            }
+        }
+        Mode::Fix => {
+            let fixed = handler.fix(&job.file_path).map_err(not_found)?;
+            let fixed_body = handler.strip_header(&fixed);
+            if fixed_body != job.script_source {
+                if let Some(start) = job.fence_start {
+                    output.patch = Some(Patch {
+                        start,
+                        end: start + job.script_source.len(),
+                        replacement: fixed_body.to_string(),
+                    });
+                }
+            }
+        }
+        Mode::Test => {
+            let expectations = parse_expectations(&job.script_source);
+            let mut findings = handler
+                .execute_structured(&job.file_path, &job.command_name)
+                .map_err(not_found)?;
+            for finding in &mut findings {
+                finding.line = finding.line.saturating_sub(handler.header_lines());
+            }
+
+            let mut matched = vec![false; findings.len()];
+            let mut missing = vec![];
+            for expectation in expectations {
+                let hit = findings.iter().enumerate().position(|(i, f)| {
+                    !matched[i] && f.line == expectation.line && f.code.as_deref() == Some(expectation.code.as_str())
+                });
+                match hit {
+                    Some(i) => matched[i] = true,
+                    None => missing.push(expectation),
+                }
+            }
+            let unexpected = findings
+                .into_iter()
+                .zip(matched)
+                .filter_map(|(finding, was_matched)| (!was_matched).then_some(finding))
+                .collect();
+
+            output.test_outcome = Some(TestOutcome { missing, unexpected });
+        }
+    }
+    Ok(output)
+}
+
+trait LanguageHandler: Display + Sync {
+    fn file_extension(&self) -> &str {
         ""
     }
     fn content(&self, script: &Script) -> Result<String, io::Error> {
         Ok(script.source.clone())
     }
     fn execute(&self, path: &Path) -> Result<String, io::Error>;
+    /// Runs the linter against `path` and returns normalized findings,
+    /// for use by the `json`/`sarif` output formats. `command` is the full
+    /// command name the findings are attributed to. Lines are reported
+    /// relative to `path`; the caller remaps them to maskfile coordinates.
+    /// The default wraps `execute`'s human-readable text as a single,
+    /// line-less finding, for handlers without structured output support.
+    fn execute_structured(&self, path: &Path, command: &str) -> Result<Vec<Finding>, io::Error> {
+        let message = self.execute(path)?;
+        if message.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![Finding {
+            command: command.to_string(),
+            line: 1,
+            column: None,
+            code: None,
+            severity: Severity::Info,
+            message,
+        }])
+    }
+    /// Runs the linter's autocorrect mode against `path` and returns the
+    /// resulting file content. The default leaves the content unchanged,
+    /// for handlers that don't support autocorrection.
+    fn fix(&self, path: &Path) -> Result<String, io::Error> {
+        fs::read_to_string(path)
+    }
+    /// Strips any header this handler injected via `content` (e.g.
+    /// Shellcheck's shebang line) so the result can be spliced back into
+    /// the maskfile.
+    fn strip_header<'a>(&self, content: &'a str) -> &'a str {
+        content
+    }
+    /// How many lines this handler injects ahead of the script body via
+    /// `content` (e.g. Shellcheck's shebang line), used to normalize
+    /// reported line numbers back to the maskfile.
+    fn header_lines(&self) -> u32 {
+        0
+    }
 }
 
 #[derive(Debug)]
@@ -132,6 +586,181 @@ impl LanguageHandler for Catchall {
     fn execute(&self, _: &Path) -> Result<String, io::Error> {
         Ok("no linter found for target".to_string())
     }
+    // The "no linter found" message is a human-facing notice, not a
+    // diagnostic; don't let the default impl turn it into a fake finding
+    // in the `json`/`sarif` output.
+    fn execute_structured(&self, _: &Path, _: &str) -> Result<Vec<Finding>, io::Error> {
+        Ok(vec![])
+    }
+}
+
+/// User-supplied linter backends, loaded from a `.masklint.toml` next to the
+/// maskfile. Lets executors like `js` or `go` get a real handler instead of
+/// falling back to `Catchall`, without a code change.
+#[derive(Deserialize, Default)]
+struct LinterConfig {
+    #[serde(default)]
+    executors: HashMap<String, ExecutorConfig>,
+}
+
+#[derive(Deserialize)]
+struct ExecutorConfig {
+    /// The linter binary to shell out to.
+    command: String,
+    /// Arguments to pass it; `{path}` is replaced with the extracted
+    /// script's temp-file path.
+    #[serde(default)]
+    args: Vec<String>,
+    /// File extension to give the extracted script, e.g. `.js`.
+    #[serde(default)]
+    extension: String,
+    /// Content prepended to the script before it's written out, e.g. a
+    /// shebang line.
+    #[serde(default)]
+    prefix: String,
+    /// Optional regex for parsing structured findings out of the linter's
+    /// stdout, one match per line. Named capture groups `line` (required),
+    /// `column`, `code`, and `message` (all optional) populate the
+    /// corresponding `Finding` fields; without this, `--format=json`/`sarif`
+    /// falls back to wrapping the whole output as a single note.
+    #[serde(default)]
+    line_regex: Option<String>,
+}
+
+/// A `LanguageHandler` built from a `.masklint.toml` `[executors.*]` entry by
+/// interpolating its command template and shelling out.
+struct ConfigHandler {
+    name: String,
+    command: String,
+    args: Vec<String>,
+    extension: String,
+    prefix: String,
+    line_regex: Option<Regex>,
+}
+
+impl Display for ConfigHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl LanguageHandler for ConfigHandler {
+    fn file_extension(&self) -> &str {
+        &self.extension
+    }
+    fn content(&self, script: &Script) -> Result<String, io::Error> {
+        Ok(format!("{}{}", self.prefix, script.source))
+    }
+    fn execute(&self, path: &Path) -> Result<String, io::Error> {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| arg.replace("{path}", &path.to_string_lossy()))
+            .collect();
+        let output = Command::new(&self.command).args(&args).output()?;
+        let findings = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .replace(&format!("{}:", path.to_string_lossy()), "line ")
+            .replace(&format!("{} ", path.to_string_lossy()), "");
+        Ok(findings)
+    }
+    fn strip_header<'a>(&self, content: &'a str) -> &'a str {
+        content.strip_prefix(&self.prefix).unwrap_or(content)
+    }
+    fn header_lines(&self) -> u32 {
+        self.prefix.matches('\n').count() as u32
+    }
+    fn execute_structured(&self, path: &Path, command: &str) -> Result<Vec<Finding>, io::Error> {
+        let Some(line_regex) = &self.line_regex else {
+            // No parse hint configured; fall back to the same one-finding
+            // wrap the default impl would have produced.
+            let message = self.execute(path)?;
+            if message.is_empty() {
+                return Ok(vec![]);
+            }
+            return Ok(vec![Finding {
+                command: command.to_string(),
+                line: 1,
+                column: None,
+                code: None,
+                severity: Severity::Info,
+                message,
+            }]);
+        };
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| arg.replace("{path}", &path.to_string_lossy()))
+            .collect();
+        let output = Command::new(&self.command).args(&args).output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .lines()
+            .filter_map(|line| line_regex.captures(line))
+            .filter_map(|captures| {
+                let line = captures.name("line")?.as_str().parse().ok()?;
+                Some(Finding {
+                    command: command.to_string(),
+                    line,
+                    column: captures.name("column").and_then(|m| m.as_str().parse().ok()),
+                    code: captures.name("code").map(|m| m.as_str().to_string()),
+                    severity: Severity::Info,
+                    message: captures
+                        .name("message")
+                        .map(|m| m.as_str().to_string())
+                        .unwrap_or_default(),
+                })
+            })
+            .collect())
+    }
+}
+
+/// Builds the executor -> handler registry: built-in handlers, overridable
+/// by `.masklint.toml` entries sitting next to the maskfile.
+fn build_registry(maskfile_path: &Path) -> HashMap<String, Box<dyn LanguageHandler>> {
+    let mut registry: HashMap<String, Box<dyn LanguageHandler>> = HashMap::new();
+    for executor in ["sh", "bash", "zsh"] {
+        registry.insert(executor.to_string(), Box::new(Shellcheck {}));
+    }
+    for executor in ["py", "python"] {
+        registry.insert(executor.to_string(), Box::new(Ruff {}));
+    }
+    for executor in ["rb", "ruby"] {
+        registry.insert(executor.to_string(), Box::new(Rubocop {}));
+    }
+
+    let config_path = maskfile_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(".masklint.toml");
+    if let Ok(config_text) = fs::read_to_string(config_path) {
+        match toml::from_str::<LinterConfig>(&config_text) {
+            Ok(config) => {
+                for (executor, cfg) in config.executors {
+                    let line_regex = cfg.line_regex.as_deref().and_then(|pattern| match Regex::new(pattern) {
+                        Ok(regex) => Some(regex),
+                        Err(e) => {
+                            eprintln!("{} invalid line_regex for executor `{executor}`: {e}", "warning:".yellow());
+                            None
+                        }
+                    });
+                    registry.insert(
+                        executor.clone(),
+                        Box::new(ConfigHandler {
+                            name: executor,
+                            command: cfg.command,
+                            args: cfg.args,
+                            extension: cfg.extension,
+                            prefix: cfg.prefix,
+                            line_regex,
+                        }),
+                    );
+                }
+            }
+            Err(e) => eprintln!("{} failed to parse .masklint.toml: {e}", "warning:".yellow()),
+        }
+    }
+    registry
 }
 
 #[derive(Debug)]
@@ -158,6 +787,61 @@ impl LanguageHandler for Shellcheck {
         res.push_str(&script.source);
         Ok(res)
     }
+    fn fix(&self, path: &Path) -> Result<String, io::Error> {
+        let output = Command::new("shellcheck")
+            .arg("--format=diff")
+            .arg(path)
+            .output()?;
+        let diff_text = String::from_utf8_lossy(&output.stdout);
+        if diff_text.trim().is_empty() {
+            return fs::read_to_string(path);
+        }
+        let original = fs::read_to_string(path)?;
+        let patch = diffy::Patch::from_str(&diff_text)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let patched = diffy::apply(&original, &patch)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, &patched)?;
+        Ok(patched)
+    }
+    fn strip_header<'a>(&self, content: &'a str) -> &'a str {
+        content.split_once('\n').map_or("", |(_, body)| body)
+    }
+    fn header_lines(&self) -> u32 {
+        1
+    }
+    fn execute_structured(&self, path: &Path, command: &str) -> Result<Vec<Finding>, io::Error> {
+        let output = Command::new("shellcheck")
+            .arg("--format=json")
+            .arg(path)
+            .output()?;
+        let comments: Vec<ShellcheckComment> =
+            serde_json::from_slice(&output.stdout).unwrap_or_default();
+        Ok(comments
+            .into_iter()
+            .map(|c| Finding {
+                command: command.to_string(),
+                line: c.line,
+                column: Some(c.column),
+                code: Some(format!("SC{}", c.code)),
+                severity: match c.level.as_str() {
+                    "error" => Severity::Error,
+                    "warning" => Severity::Warning,
+                    _ => Severity::Info,
+                },
+                message: c.message,
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize)]
+struct ShellcheckComment {
+    line: u32,
+    column: u32,
+    level: String,
+    code: u32,
+    message: String,
 }
 
 struct Ruff;
@@ -194,6 +878,48 @@ impl LanguageHandler for Ruff {
         }
         Ok(valid_lines.join("\n").trim().to_string())
     }
+    fn fix(&self, path: &Path) -> Result<String, io::Error> {
+        Command::new("ruff")
+            .arg("check")
+            .arg("--fix")
+            .arg("--no-cache")
+            .arg(path)
+            .output()?;
+        fs::read_to_string(path)
+    }
+    fn execute_structured(&self, path: &Path, command: &str) -> Result<Vec<Finding>, io::Error> {
+        let output = Command::new("ruff")
+            .arg("check")
+            .arg("--output-format=json")
+            .arg("--no-cache")
+            .arg(path)
+            .output()?;
+        let messages: Vec<RuffMessage> = serde_json::from_slice(&output.stdout).unwrap_or_default();
+        Ok(messages
+            .into_iter()
+            .map(|m| Finding {
+                command: command.to_string(),
+                line: m.location.row,
+                column: Some(m.location.column),
+                code: m.code,
+                severity: Severity::Warning,
+                message: m.message,
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize)]
+struct RuffMessage {
+    code: Option<String>,
+    message: String,
+    location: RuffLocation,
+}
+
+#[derive(Deserialize)]
+struct RuffLocation {
+    row: u32,
+    column: u32,
 }
 
 struct Rubocop;
@@ -222,4 +948,61 @@ impl LanguageHandler for Rubocop {
             .replace(&format!("{}:", path.to_string_lossy()), "line ");
         Ok(findings)
     }
+    fn fix(&self, path: &Path) -> Result<String, io::Error> {
+        Command::new("rubocop")
+            .arg("-a")
+            .arg(path)
+            .output()?;
+        fs::read_to_string(path)
+    }
+    fn execute_structured(&self, path: &Path, command: &str) -> Result<Vec<Finding>, io::Error> {
+        let output = Command::new("rubocop")
+            .arg("--format=json")
+            .arg(path)
+            .output()?;
+        let report: RubocopReport = serde_json::from_slice(&output.stdout).unwrap_or(RubocopReport {
+            files: vec![],
+        });
+        Ok(report
+            .files
+            .into_iter()
+            .flat_map(|f| f.offenses)
+            .map(|o| Finding {
+                command: command.to_string(),
+                line: o.location.line,
+                column: Some(o.location.column),
+                code: Some(o.cop_name),
+                severity: match o.severity.as_str() {
+                    "error" | "fatal" => Severity::Error,
+                    "warning" => Severity::Warning,
+                    _ => Severity::Info,
+                },
+                message: o.message,
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize)]
+struct RubocopReport {
+    files: Vec<RubocopFile>,
+}
+
+#[derive(Deserialize)]
+struct RubocopFile {
+    offenses: Vec<RubocopOffense>,
+}
+
+#[derive(Deserialize)]
+struct RubocopOffense {
+    severity: String,
+    message: String,
+    cop_name: String,
+    location: RubocopLocation,
+}
+
+#[derive(Deserialize)]
+struct RubocopLocation {
+    line: u32,
+    column: u32,
 }